@@ -0,0 +1,26 @@
+use std::env;
+use std::fs;
+
+use flow_studio_dev::load;
+
+#[tokio::main]
+async fn main() {
+    let path = env::args().nth(1).expect("usage: loadtest <plan.yaml>");
+    let yaml =
+        fs::read_to_string(&path).unwrap_or_else(|err| panic!("failed to read {}: {}", path, err));
+    let plan =
+        load::parse(&yaml).unwrap_or_else(|err| panic!("failed to parse {}: {}", path, err));
+
+    let report = load::run(&plan).await;
+    for (name, stats) in &report.steps {
+        println!(
+            "{name}: {count} reqs, p50={p50:?} p90={p90:?} p99={p99:?} throughput={tp:.1}/s",
+            name = name,
+            count = stats.count,
+            p50 = stats.p50,
+            p90 = stats.p90,
+            p99 = stats.p99,
+            tp = stats.throughput_per_sec,
+        );
+    }
+}
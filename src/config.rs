@@ -0,0 +1,18 @@
+use std::net::SocketAddr;
+
+/// Configuration for a single downstream dependency probed by
+/// `/health/ready`.
+#[derive(Clone, Debug)]
+pub struct ComponentConfig {
+    pub enabled: bool,
+    pub addr: SocketAddr,
+}
+
+/// Which downstream dependencies the health subsystem should probe, and
+/// where to find them. A component left as `None`, or with `enabled:
+/// false`, is skipped rather than reported as failing.
+#[derive(Clone, Debug, Default)]
+pub struct HealthConfig {
+    pub database: Option<ComponentConfig>,
+    pub cache: Option<ComponentConfig>,
+}
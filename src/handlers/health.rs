@@ -1,9 +1,113 @@
-// Minimal handler for health endpoint (dry-run)
-pub fn health() -> &'static str {
-    "{ \"status\": \"ok\" }"
-}
-// Minimal handler scaffold for health endpoint (toy placeholder)
-pub async fn health_handler() -> &'static str {
-    // In a real app this would return an HTTP response object; kept minimal for dry-run.
-    "{ \"status\": \"ok\" }"
+use std::collections::BTreeMap;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Serialize;
+
+use crate::health::ComponentStatus;
+use crate::state::AppState;
+
+/// Routes exposed by the health subsystem.
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/health", get(health_handler))
+        .route("/health/ready", get(readiness_handler))
+}
+
+#[derive(Serialize)]
+struct HealthResponse {
+    status: &'static str,
+    uptime_seconds: u64,
+}
+
+/// Cheap liveness probe: returns 200 as soon as the process can handle
+/// requests, without checking any downstream dependencies.
+async fn health_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let body = HealthResponse {
+        status: "ok",
+        uptime_seconds: state.uptime().as_secs(),
+    };
+    (StatusCode::OK, Json(body))
+}
+
+#[derive(Serialize)]
+struct ReadinessResponse {
+    status: &'static str,
+    checks: BTreeMap<String, ComponentStatus>,
+}
+
+/// Readiness probe: checks every registered downstream dependency and
+/// returns 200 only if all of them are healthy, 503 otherwise.
+async fn readiness_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let report = state.health().run().await;
+
+    let status_code = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    let body = ReadinessResponse {
+        status: if report.ready { "ok" } else { "degraded" },
+        checks: report.components,
+    };
+
+    (status_code, Json(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::body::to_bytes;
+
+    use super::*;
+    use crate::health::{HealthRegistry, RepositoryHealthCheck};
+    use crate::service::{MockRepository, Repository, RepositoryError, SystemClock};
+
+    fn state_with_repository(repository: Arc<dyn Repository>) -> AppState {
+        let mut registry = HealthRegistry::new();
+        registry.register(Arc::new(RepositoryHealthCheck::new(
+            "database",
+            repository.clone(),
+        )));
+        AppState::new(repository, Arc::new(SystemClock), registry)
+    }
+
+    async fn readiness_json(state: AppState) -> (StatusCode, serde_json::Value) {
+        let response = readiness_handler(State(state)).await.into_response();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("failed to read response body");
+        (status, serde_json::from_slice(&body).expect("response was not valid JSON"))
+    }
+
+    #[tokio::test]
+    async fn readiness_reports_ok_when_repository_is_healthy() {
+        let mut mock = MockRepository::new();
+        mock.expect_ping().returning(|| Ok(()));
+
+        let (status, body) = readiness_json(state_with_repository(Arc::new(mock))).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "ok");
+        assert_eq!(body["checks"]["database"]["status"], "ok");
+    }
+
+    #[tokio::test]
+    async fn readiness_reports_degraded_when_repository_is_unreachable() {
+        let mut mock = MockRepository::new();
+        mock.expect_ping()
+            .returning(|| Err(RepositoryError("connection refused".to_string())));
+
+        let (status, body) = readiness_json(state_with_repository(Arc::new(mock))).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "degraded");
+        assert_eq!(body["checks"]["database"]["status"], "error");
+    }
 }
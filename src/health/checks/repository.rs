@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::health::{CheckResult, HealthCheck};
+use crate::service::Repository;
+
+/// Adapts any [`Repository`] into a [`HealthCheck`] so the datastore is
+/// probed through `/health/ready` the same way as any other downstream
+/// dependency, and can be swapped for a mock in tests.
+pub struct RepositoryHealthCheck {
+    name: String,
+    repository: Arc<dyn Repository>,
+}
+
+impl RepositoryHealthCheck {
+    pub fn new(name: impl Into<String>, repository: Arc<dyn Repository>) -> Self {
+        Self {
+            name: name.into(),
+            repository,
+        }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for RepositoryHealthCheck {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn check(&self) -> CheckResult {
+        match self.repository.ping().await {
+            Ok(()) => CheckResult::Healthy,
+            Err(err) => CheckResult::Unhealthy(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::net::SocketAddr;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use tokio::net::TcpStream;
+
+use crate::health::{CheckResult, HealthCheck};
+
+/// Generic "can we open a TCP connection" check, used for dependencies
+/// like the database or cache that only need a reachability probe.
+///
+/// The target address can be updated after construction via
+/// [`TcpCheck::resolve`] — useful when a component is bound to port `0`
+/// (OS-assigned) and its real address is only known after it starts.
+pub struct TcpCheck {
+    name: String,
+    addr: RwLock<SocketAddr>,
+}
+
+impl TcpCheck {
+    pub fn new(name: impl Into<String>, addr: SocketAddr) -> Self {
+        Self {
+            name: name.into(),
+            addr: RwLock::new(addr),
+        }
+    }
+
+    /// Updates the address this check probes, e.g. once a component
+    /// originally bound to port 0 has resolved its OS-assigned port.
+    pub fn resolve(&self, addr: SocketAddr) {
+        *self.addr.write().expect("tcp check addr lock poisoned") = addr;
+    }
+
+    fn addr(&self) -> SocketAddr {
+        *self.addr.read().expect("tcp check addr lock poisoned")
+    }
+}
+
+#[async_trait]
+impl HealthCheck for TcpCheck {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn check(&self) -> CheckResult {
+        match TcpStream::connect(self.addr()).await {
+            Ok(_) => CheckResult::Healthy,
+            Err(err) => CheckResult::Unhealthy(err.to_string()),
+        }
+    }
+}
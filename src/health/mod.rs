@@ -0,0 +1,87 @@
+mod checks;
+mod registry;
+
+pub use checks::repository::RepositoryHealthCheck;
+pub use checks::tcp::TcpCheck;
+pub use registry::{ComponentStatus, HealthRegistry, ReadinessReport};
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::config::HealthConfig;
+use crate::service::Repository;
+
+/// Outcome of a single dependency probe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckResult {
+    Healthy,
+    Unhealthy(String),
+}
+
+impl CheckResult {
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, CheckResult::Healthy)
+    }
+}
+
+/// A single downstream dependency that readiness probing can verify.
+///
+/// Implementations register themselves with a [`HealthRegistry`] so new
+/// dependencies (a cache, a message queue, ...) only need to implement
+/// this trait and be added at startup; nothing else in the health
+/// subsystem has to change.
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    /// Stable name used as the JSON key in the readiness response.
+    fn name(&self) -> &str;
+
+    /// Probe the dependency. Called on every `/health/ready` request.
+    async fn check(&self) -> CheckResult;
+}
+
+/// Handles to registered checks whose target address may need to be
+/// resolved later, e.g. a component configured on port 0 (OS-assigned)
+/// whose real port is only known once it has actually started. Whoever
+/// starts that component must call [`TcpCheck::resolve`] on the matching
+/// handle as soon as its real address is known, and before the first
+/// `/health/ready` probe. `main` has no such component today — every
+/// `HealthHandles` field it gets back is unused — but the integration
+/// test harness (`tests/common::spawn_app_with`) returns these handles,
+/// and the readiness test resolves its cache handle the same way a
+/// future caller with a real port-0 component would.
+#[derive(Default, Clone)]
+pub struct HealthHandles {
+    pub cache: Option<Arc<TcpCheck>>,
+}
+
+/// Builds the set of checks that `/health/ready` should probe, skipping
+/// any component that is disabled in config rather than registering it
+/// as a check that always fails.
+///
+/// The database check is probed through `repository` (the same
+/// [`Repository`] the rest of the app depends on) rather than opening its
+/// own connection, so a mocked repository in tests is reflected here too.
+pub fn build_registry(
+    config: &HealthConfig,
+    repository: Arc<dyn Repository>,
+) -> (HealthRegistry, HealthHandles) {
+    let mut registry = HealthRegistry::new();
+    let mut handles = HealthHandles::default();
+
+    if let Some(database) = &config.database {
+        if database.enabled {
+            registry.register(Arc::new(RepositoryHealthCheck::new("database", repository)));
+        }
+    }
+
+    if let Some(cache) = &config.cache {
+        if cache.enabled {
+            let check = Arc::new(TcpCheck::new("cache", cache.addr));
+            handles.cache = Some(check.clone());
+            registry.register(check);
+        }
+    }
+
+    (registry, handles)
+}
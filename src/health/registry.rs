@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use super::{CheckResult, HealthCheck};
+
+/// Holds every [`HealthCheck`] that `/health/ready` should probe.
+///
+/// Cloning a registry clones the `Vec` of `Arc`s, not the checks
+/// themselves, so it can be shared through [`crate::state::AppState`]
+/// like any other piece of shared state.
+#[derive(Clone, Default)]
+pub struct HealthRegistry {
+    checks: Vec<Arc<dyn HealthCheck>>,
+}
+
+impl HealthRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a dependency check. Disabled components must not be
+    /// registered at all, rather than registered and reported as failing.
+    pub fn register(&mut self, check: Arc<dyn HealthCheck>) {
+        self.checks.push(check);
+    }
+
+    /// Runs every registered check and aggregates the results. Overall
+    /// readiness is `true` only if every registered check passed.
+    pub async fn run(&self) -> ReadinessReport {
+        let mut components = BTreeMap::new();
+        let mut ready = true;
+
+        for check in &self.checks {
+            let result = check.check().await;
+            if !result.is_healthy() {
+                ready = false;
+            }
+            components.insert(check.name().to_string(), ComponentStatus::from(result));
+        }
+
+        ReadinessReport { ready, components }
+    }
+}
+
+/// Per-component status reported in the `/health/ready` JSON body.
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum ComponentStatus {
+    Ok,
+    Error { reason: String },
+}
+
+impl From<CheckResult> for ComponentStatus {
+    fn from(result: CheckResult) -> Self {
+        match result {
+            CheckResult::Healthy => ComponentStatus::Ok,
+            CheckResult::Unhealthy(reason) => ComponentStatus::Error { reason },
+        }
+    }
+}
+
+/// Aggregated result of running every registered [`HealthCheck`].
+pub struct ReadinessReport {
+    pub ready: bool,
+    pub components: BTreeMap<String, ComponentStatus>,
+}
@@ -0,0 +1,19 @@
+pub mod config;
+pub mod handlers;
+pub mod health;
+pub mod load;
+mod macros;
+pub mod service;
+pub mod state;
+
+use axum::Router;
+
+pub use state::AppState;
+
+/// Builds the application's Axum router, wiring handlers to routes and
+/// injecting `state` via Axum's typed `State` extractor.
+pub fn build_router(state: AppState) -> Router {
+    Router::new()
+        .merge(handlers::health::routes())
+        .with_state(state)
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Values captured by `assign` during a run, keyed by step name, plus the
+/// current `with_items` element (if any) under `item`.
+#[derive(Default, Clone)]
+pub struct Context {
+    values: HashMap<String, Value>,
+}
+
+impl Context {
+    pub fn set(&mut self, name: &str, value: Value) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn set_item(&mut self, item: Value) {
+        self.values.insert("item".to_string(), item);
+    }
+
+    /// Resolves a dotted path like `foo.body.id` against captured values.
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        let mut parts = path.split('.');
+        let mut current = self.values.get(parts.next()?)?;
+        for part in parts {
+            current = current.get(part)?;
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_resolves_a_nested_dotted_path() {
+        let mut ctx = Context::default();
+        ctx.set("signup", serde_json::json!({ "status": 201, "body": { "id": "abc123" } }));
+
+        assert_eq!(ctx.get("signup.body.id"), Some(&Value::String("abc123".to_string())));
+    }
+
+    #[test]
+    fn get_resolves_the_current_with_items_element_under_item() {
+        let mut ctx = Context::default();
+        ctx.set_item(serde_json::json!("widget-1"));
+
+        assert_eq!(ctx.get("item"), Some(&Value::String("widget-1".to_string())));
+    }
+
+    #[test]
+    fn get_returns_none_for_an_unset_name() {
+        let ctx = Context::default();
+
+        assert_eq!(ctx.get("signup.body.id"), None);
+    }
+
+    #[test]
+    fn get_returns_none_for_an_unset_nested_field() {
+        let mut ctx = Context::default();
+        ctx.set("signup", serde_json::json!({ "body": {} }));
+
+        assert_eq!(ctx.get("signup.body.id"), None);
+    }
+}
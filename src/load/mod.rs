@@ -0,0 +1,15 @@
+//! YAML-driven load/benchmark runner for the service's own endpoints.
+//!
+//! A [`Plan`] describes concurrency, iteration count, rampup, a base URL
+//! and a list of named [`Step`]s. [`run`] drives the plan and returns
+//! latency percentiles and throughput per step.
+
+mod context;
+mod plan;
+mod report;
+mod runner;
+mod template;
+
+pub use plan::{parse, Assertion, Plan, Step};
+pub use report::{Report, StepReport};
+pub use runner::run;
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A load-test plan loaded from YAML: how hard to drive the target and
+/// which requests to send.
+#[derive(Debug, Deserialize)]
+pub struct Plan {
+    pub concurrency: usize,
+    pub iterations: usize,
+    /// Seconds over which workers ramp up to full concurrency.
+    #[serde(default)]
+    pub rampup: f64,
+    pub base: String,
+    pub steps: Vec<Step>,
+}
+
+/// A single named request in a [`Plan`]. `{{ name.body.field }}`
+/// placeholders in `path` are resolved against values captured by earlier
+/// steps' `assign`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Step {
+    pub name: String,
+    pub method: String,
+    pub path: String,
+    /// Captures this step's result (`status` + `body`) under `name` in
+    /// the run's template context, for later steps to reference.
+    #[serde(default)]
+    pub assign: Option<String>,
+    /// Repeats this step once per item, with `{{ item }}` bound to the
+    /// current element.
+    #[serde(default)]
+    pub with_items: Option<Vec<Value>>,
+    /// Fails the run if any of these don't hold once the step completes.
+    #[serde(default)]
+    pub assert: Vec<Assertion>,
+}
+
+/// Fails the run if the value at the dotted context `path` (e.g.
+/// `foo.body.status`) isn't `equals`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Assertion {
+    pub path: String,
+    pub equals: Value,
+}
+
+/// Parses a load-test plan from its YAML source.
+pub fn parse(yaml: &str) -> Result<Plan, serde_yaml::Error> {
+    serde_yaml::from_str(yaml)
+}
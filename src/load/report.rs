@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Latency/throughput summary for one named step, aggregated across every
+/// iteration and every concurrent worker.
+#[derive(Debug, Clone)]
+pub struct StepReport {
+    pub count: usize,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub throughput_per_sec: f64,
+}
+
+/// Aggregated result of a load-test run: one [`StepReport`] per step name.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub steps: BTreeMap<String, StepReport>,
+}
+
+/// Computes percentile/throughput stats from a step's raw latency samples
+/// collected over `wall_clock`.
+pub fn summarize(mut samples: Vec<Duration>, wall_clock: Duration) -> StepReport {
+    samples.sort();
+    let count = samples.len();
+
+    let percentile = |p: f64| -> Duration {
+        if samples.is_empty() {
+            return Duration::ZERO;
+        }
+        let idx = ((count as f64 * p).ceil() as usize)
+            .saturating_sub(1)
+            .min(count - 1);
+        samples[idx]
+    };
+
+    StepReport {
+        count,
+        p50: percentile(0.50),
+        p90: percentile(0.90),
+        p99: percentile(0.99),
+        throughput_per_sec: if wall_clock.as_secs_f64() > 0.0 {
+            count as f64 / wall_clock.as_secs_f64()
+        } else {
+            0.0
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarize_single_sample_reports_it_at_every_percentile() {
+        let report = summarize(vec![Duration::from_millis(42)], Duration::from_secs(1));
+
+        assert_eq!(report.count, 1);
+        assert_eq!(report.p50, Duration::from_millis(42));
+        assert_eq!(report.p90, Duration::from_millis(42));
+        assert_eq!(report.p99, Duration::from_millis(42));
+    }
+
+    #[test]
+    fn summarize_known_latencies_picks_the_ceil_count_times_p_minus_one_sample() {
+        // Sorted 1..=100ms; p50 -> ceil(100*0.5)-1 = 49 -> 50ms,
+        // p90 -> ceil(100*0.9)-1 = 89 -> 90ms, p99 -> ceil(100*0.99)-1 = 98 -> 99ms.
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+
+        let report = summarize(samples, Duration::from_secs(1));
+
+        assert_eq!(report.count, 100);
+        assert_eq!(report.p50, Duration::from_millis(50));
+        assert_eq!(report.p90, Duration::from_millis(90));
+        assert_eq!(report.p99, Duration::from_millis(99));
+    }
+
+    #[test]
+    fn summarize_empty_samples_reports_zero_latency() {
+        let report = summarize(Vec::new(), Duration::from_secs(1));
+
+        assert_eq!(report.count, 0);
+        assert_eq!(report.p50, Duration::ZERO);
+        assert_eq!(report.throughput_per_sec, 0.0);
+    }
+
+    #[test]
+    fn summarize_computes_throughput_from_wall_clock() {
+        let samples: Vec<Duration> = (0..10).map(|_| Duration::from_millis(1)).collect();
+
+        let report = summarize(samples, Duration::from_secs(2));
+
+        assert_eq!(report.count, 10);
+        assert_eq!(report.throughput_per_sec, 5.0);
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use serde_json::Value;
+
+use super::context::Context;
+use super::plan::{Assertion, Plan, Step};
+use super::report::{summarize, Report};
+use super::template::render;
+
+/// Drives `plan` against its configured `base` URL and returns latency
+/// percentiles/throughput per step.
+///
+/// Workers ramp up linearly over `plan.rampup` seconds so the target
+/// isn't hit with the full concurrency instantaneously; each worker then
+/// runs `plan.iterations` passes through every step in order.
+pub async fn run(plan: &Plan) -> Report {
+    let client = Client::new();
+    let samples: Mutex<BTreeMap<String, Vec<Duration>>> = Mutex::new(BTreeMap::new());
+
+    let started = Instant::now();
+    let mut workers = Vec::with_capacity(plan.concurrency);
+
+    for worker_idx in 0..plan.concurrency {
+        let client = client.clone();
+        let base = plan.base.clone();
+        let steps = plan.steps.clone();
+        let iterations = plan.iterations;
+        let delay = ramp_delay(plan.rampup, plan.concurrency, worker_idx);
+
+        workers.push(tokio::spawn(async move {
+            if delay > Duration::ZERO {
+                tokio::time::sleep(delay).await;
+            }
+
+            let mut local: BTreeMap<String, Vec<Duration>> = BTreeMap::new();
+            for _ in 0..iterations {
+                let mut ctx = Context::default();
+                for step in &steps {
+                    run_step(&client, &base, step, &mut ctx, &mut local).await;
+                }
+            }
+            local
+        }));
+    }
+
+    for worker in workers {
+        let local = worker.await.expect("load-test worker panicked");
+        let mut all = samples.lock().expect("samples lock poisoned");
+        for (name, mut durations) in local {
+            all.entry(name).or_default().append(&mut durations);
+        }
+    }
+
+    let wall_clock = started.elapsed();
+    let samples = samples.into_inner().expect("samples lock poisoned");
+    Report {
+        steps: samples
+            .into_iter()
+            .map(|(name, durations)| (name, summarize(durations, wall_clock)))
+            .collect(),
+    }
+}
+
+async fn run_step(
+    client: &Client,
+    base: &str,
+    step: &Step,
+    ctx: &mut Context,
+    samples: &mut BTreeMap<String, Vec<Duration>>,
+) {
+    let items = step.with_items.clone().unwrap_or_else(|| vec![Value::Null]);
+
+    for item in items {
+        if !item.is_null() {
+            ctx.set_item(item);
+        }
+
+        let path = render(&step.path, ctx);
+        let url = format!("{}{}", base, path);
+
+        let started = Instant::now();
+        let response = client
+            .request(
+                step.method
+                    .parse()
+                    .unwrap_or_else(|_| panic!("invalid HTTP method `{}` in load plan", step.method)),
+                &url,
+            )
+            .send()
+            .await
+            .unwrap_or_else(|err| panic!("load-test step `{}` request failed: {}", step.name, err));
+
+        let status = response.status().as_u16();
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        let elapsed = started.elapsed();
+
+        samples.entry(step.name.clone()).or_default().push(elapsed);
+
+        if let Some(assign) = &step.assign {
+            ctx.set(assign, serde_json::json!({ "status": status, "body": body }));
+        }
+
+        for assertion in &step.assert {
+            check_assertion(assertion, ctx);
+        }
+    }
+}
+
+fn check_assertion(assertion: &Assertion, ctx: &Context) {
+    let actual = ctx
+        .get(&assertion.path)
+        .unwrap_or_else(|| panic!("assert path `{}` not found in context", assertion.path));
+    assert_eq!(
+        actual, &assertion.equals,
+        "load-test assertion failed for `{}`",
+        assertion.path
+    );
+}
+
+fn ramp_delay(rampup: f64, concurrency: usize, worker_idx: usize) -> Duration {
+    if rampup <= 0.0 || concurrency <= 1 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs_f64(rampup * worker_idx as f64 / concurrency as f64)
+}
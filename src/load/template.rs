@@ -0,0 +1,82 @@
+use super::context::Context;
+
+/// Replaces every `{{ path }}` placeholder in `input` with the
+/// corresponding value from `ctx`. String values are rendered bare (no
+/// surrounding quotes) so the result drops straight into a URL.
+pub fn render(input: &str, ctx: &Context) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after_start = &rest[start + 2..];
+        let end = after_start
+            .find("}}")
+            .unwrap_or_else(|| panic!("unterminated {{{{ }}}} in template `{}`", input));
+        let path = after_start[..end].trim();
+
+        let value = ctx
+            .get(path)
+            .unwrap_or_else(|| panic!("template path `{}` not found in context", path));
+        out.push_str(&value_to_string(value));
+
+        rest = &after_start[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_interpolates_a_string_value_without_quotes() {
+        let mut ctx = Context::default();
+        ctx.set("signup", serde_json::json!({ "body": { "id": "abc123" } }));
+
+        let rendered = render("/users/{{ signup.body.id }}", &ctx);
+
+        assert_eq!(rendered, "/users/abc123");
+    }
+
+    #[test]
+    fn render_interpolates_a_non_string_value_via_its_json_representation() {
+        let mut ctx = Context::default();
+        ctx.set("signup", serde_json::json!({ "body": { "id": 42 } }));
+
+        let rendered = render("/users/{{ signup.body.id }}", &ctx);
+
+        assert_eq!(rendered, "/users/42");
+    }
+
+    #[test]
+    fn render_passes_through_input_with_no_placeholders() {
+        let ctx = Context::default();
+
+        assert_eq!(render("/health", &ctx), "/health");
+    }
+
+    #[test]
+    #[should_panic(expected = "not found in context")]
+    fn render_panics_on_missing_path() {
+        let ctx = Context::default();
+
+        render("/users/{{ signup.body.id }}", &ctx);
+    }
+
+    #[test]
+    #[should_panic(expected = "unterminated")]
+    fn render_panics_on_unterminated_placeholder() {
+        let ctx = Context::default();
+
+        render("/users/{{ signup.body.id", &ctx);
+    }
+}
@@ -0,0 +1,93 @@
+/// Declarative black-box assertion against a running test server.
+///
+/// Sends the given `$method` request (any HTTP method `reqwest::Method`
+/// knows — `GET`, `POST`, `PUT`, `DELETE`, ...) to `$path` against `$app`
+/// (the handle returned by `spawn_app()` — see the integration-test
+/// harness in `tests/common`), deserializes the response body as `$ty`,
+/// and asserts both the status code and the named fields. The status
+/// defaults to 200 if omitted.
+///
+/// The field list is exhaustive by default: it must name every field of
+/// `$ty`, so a field added to the response type without updating the
+/// assertion is a compile error rather than a silently-passing test.
+/// Write a trailing `..` to assert only the listed subset instead:
+///
+/// ```ignore
+/// assert_api! { app, GET "/health" => HealthResponse { status: "ok".to_string() } }
+/// assert_api! { app, GET "/health/ready" => 503, ReadinessResponse { status: "degraded".to_string(), .. } }
+/// assert_api! { app, POST "/widgets" => 201, Widget { id: 1, .. } }
+/// ```
+///
+/// On a field mismatch the panic message includes the whole deserialized
+/// body, not just the offending field, so a failure is debuggable without
+/// re-running the test with extra logging.
+#[macro_export]
+macro_rules! assert_api {
+    ($app:expr, $method:ident $path:expr => $status:expr, $ty:path { $($field:ident : $val:expr),* $(,)? }) => {{
+        let actual = $crate::assert_api!(@send $app, $method, $path, $status, $ty);
+        // No trailing `..`: naming every field here is required for this to
+        // compile, so a field added to `$ty` later can't go unchecked.
+        let $ty { $($field: $field),* } = &actual;
+        $(
+            assert_eq!(
+                *$field,
+                $val,
+                "assert_api!: {} {} — field `{}` did not match\n  actual: {:#?}",
+                stringify!($method),
+                $path,
+                stringify!($field),
+                actual
+            );
+        )*
+    }};
+
+    ($app:expr, $method:ident $path:expr => $status:expr, $ty:path { $($field:ident : $val:expr),+ , .. $(,)? }) => {{
+        let actual = $crate::assert_api!(@send $app, $method, $path, $status, $ty);
+        $(
+            assert_eq!(
+                actual.$field,
+                $val,
+                "assert_api!: {} {} — field `{}` did not match\n  actual: {:#?}",
+                stringify!($method),
+                $path,
+                stringify!($field),
+                actual
+            );
+        )+
+    }};
+
+    ($app:expr, $method:ident $path:expr => $ty:path { $($field:ident : $val:expr),* $(,)? }) => {
+        $crate::assert_api! { $app, $method $path => 200, $ty { $($field : $val),* } }
+    };
+
+    ($app:expr, $method:ident $path:expr => $ty:path { $($field:ident : $val:expr),+ , .. $(,)? }) => {
+        $crate::assert_api! { $app, $method $path => 200, $ty { $($field : $val),+, .. } }
+    };
+
+    (@send $app:expr, $method:ident, $path:expr, $status:expr, $ty:path) => {{
+        let method: reqwest::Method = stringify!($method)
+            .parse()
+            .unwrap_or_else(|_| panic!("assert_api!: unsupported HTTP method `{}`", stringify!($method)));
+        let response = $app
+            .client
+            .request(method, $app.url($path))
+            .send()
+            .await
+            .expect("assert_api!: request failed");
+
+        let status = response.status();
+        assert_eq!(
+            status,
+            $status,
+            "assert_api!: {} {} returned unexpected status",
+            stringify!($method),
+            $path
+        );
+
+        let actual: $ty = response
+            .json()
+            .await
+            .expect("assert_api!: response body was not valid JSON");
+        actual
+    }};
+}
@@ -0,0 +1,42 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use flow_studio_dev::config::HealthConfig;
+use flow_studio_dev::service::{Repository, SystemClock, TcpRepository};
+use flow_studio_dev::{build_router, health, AppState};
+
+#[tokio::main]
+async fn main() {
+    // TODO: load from the real config source once one exists; for now the
+    // health subsystem simply has no downstream dependencies configured.
+    let health_config = HealthConfig::default();
+
+    let database_addr: SocketAddr = health_config
+        .database
+        .as_ref()
+        .map(|component| component.addr)
+        .unwrap_or_else(|| "127.0.0.1:5432".parse().expect("valid default database addr"));
+    let repository: Arc<dyn Repository> = Arc::new(TcpRepository::new(database_addr));
+    let clock = Arc::new(SystemClock);
+
+    // `build_registry` also returns a `HealthHandles`, used to resolve a
+    // component configured on port 0 (OS-assigned) to its real address
+    // once known — see `health::HealthHandles`. Every component this
+    // process depends on today has a fixed, pre-known address, so there
+    // is nothing for this binary to resolve; `_handles` is unused here.
+    // A deployment that starts one of its own downstream components
+    // on port 0 would resolve it through the returned handle before the
+    // first `/health/ready` probe, the same way the readiness test
+    // resolves the cache handle that `tests/common::spawn_app_with` returns.
+    let (registry, _handles) = health::build_registry(&health_config, repository.clone());
+    let state = AppState::new(repository, clock, registry);
+    let router = build_router(state);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080")
+        .await
+        .expect("failed to bind listener");
+
+    axum::serve(listener, router)
+        .await
+        .expect("server error");
+}
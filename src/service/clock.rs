@@ -0,0 +1,18 @@
+use std::time::SystemTime;
+
+/// Abstracts "what time is it" so time-dependent logic (uptime, TTLs,
+/// expiry) can be unit-tested without waiting on a real clock.
+#[cfg_attr(test, mockall::automock)]
+pub trait Clock: Send + Sync {
+    fn now(&self) -> SystemTime;
+}
+
+/// Production [`Clock`] backed by the system clock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
@@ -0,0 +1,12 @@
+//! Trait-based service layer: handlers and health checks depend on these
+//! traits rather than concrete infrastructure, so unit tests can swap in
+//! mocks instead of spinning up a real database or waiting on the clock.
+
+pub mod clock;
+pub mod repository;
+
+pub use clock::{Clock, SystemClock};
+pub use repository::{Repository, RepositoryError, TcpRepository};
+
+#[cfg(test)]
+pub use repository::MockRepository;
@@ -0,0 +1,51 @@
+use std::fmt;
+use std::net::SocketAddr;
+
+use async_trait::async_trait;
+use tokio::net::TcpStream;
+
+/// The application's primary datastore, abstracted so handlers and health
+/// checks depend on this trait rather than a concrete database client.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    /// Cheapest possible round-trip to confirm the datastore is reachable.
+    async fn ping(&self) -> Result<(), RepositoryError>;
+}
+
+/// Error returned by a failed [`Repository`] operation.
+#[derive(Debug, Clone)]
+pub struct RepositoryError(pub String);
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "repository error: {}", self.0)
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
+/// Production [`Repository`] that checks reachability over TCP.
+///
+/// Stands in for a real database client; swap it for one (e.g. a
+/// `sqlx::PgPool` wrapper) without touching any code that depends on
+/// [`Repository`].
+pub struct TcpRepository {
+    addr: SocketAddr,
+}
+
+impl TcpRepository {
+    pub fn new(addr: SocketAddr) -> Self {
+        Self { addr }
+    }
+}
+
+#[async_trait]
+impl Repository for TcpRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        TcpStream::connect(self.addr)
+            .await
+            .map(|_| ())
+            .map_err(|err| RepositoryError(err.to_string()))
+    }
+}
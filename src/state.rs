@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use crate::health::HealthRegistry;
+use crate::service::{Clock, Repository};
+
+/// Shared application state, injected into handlers via Axum's `State`
+/// extractor. Cheap to clone: the actual data lives behind an `Arc`.
+#[derive(Clone)]
+pub struct AppState {
+    inner: Arc<AppStateInner>,
+}
+
+struct AppStateInner {
+    started_at: SystemTime,
+    clock: Arc<dyn Clock>,
+    repository: Arc<dyn Repository>,
+    health: HealthRegistry,
+}
+
+impl AppState {
+    pub fn new(repository: Arc<dyn Repository>, clock: Arc<dyn Clock>, health: HealthRegistry) -> Self {
+        Self {
+            inner: Arc::new(AppStateInner {
+                started_at: clock.now(),
+                clock,
+                repository,
+                health,
+            }),
+        }
+    }
+
+    /// How long the process has been running.
+    pub fn uptime(&self) -> Duration {
+        self.inner
+            .clock
+            .now()
+            .duration_since(self.inner.started_at)
+            .unwrap_or_default()
+    }
+
+    /// The application's primary datastore, depended on via [`Repository`]
+    /// rather than a concrete client so it can be mocked in tests.
+    pub fn repository(&self) -> &Arc<dyn Repository> {
+        &self.inner.repository
+    }
+
+    /// The registry of downstream dependency checks used by
+    /// `/health/ready`.
+    pub fn health(&self) -> &HealthRegistry {
+        &self.inner.health
+    }
+}
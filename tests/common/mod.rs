@@ -0,0 +1,67 @@
+// Not every integration test binary uses every helper here (each test file
+// gets its own copy of this module), so an unused one isn't dead code.
+#![allow(dead_code)]
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use flow_studio_dev::config::HealthConfig;
+use flow_studio_dev::health::HealthHandles;
+use flow_studio_dev::service::{Repository, SystemClock, TcpRepository};
+use flow_studio_dev::{build_router, health, AppState};
+
+/// A running instance of the application, bound to a random OS-assigned
+/// port so tests can run concurrently without colliding on a fixed one.
+pub struct TestApp {
+    pub address: SocketAddr,
+    pub client: reqwest::Client,
+}
+
+impl TestApp {
+    /// Builds a full `http://{address}{path}` URL for this instance.
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.address, path)
+    }
+}
+
+/// Spawns the application in the background, bound to a random port, with
+/// no downstream dependencies configured.
+pub async fn spawn_app() -> TestApp {
+    // No components are enabled in the default config, so this address is
+    // never actually dialed; it only satisfies the constructor.
+    let repository: Arc<dyn Repository> =
+        Arc::new(TcpRepository::new("127.0.0.1:0".parse().expect("valid addr")));
+    spawn_app_with(HealthConfig::default(), repository).await.0
+}
+
+/// Spawns the application in the background with the given health config
+/// and repository, bound to a random port. Returns the [`HealthHandles`]
+/// alongside the app so a test can resolve a port-0 component's real
+/// address before probing it.
+pub async fn spawn_app_with(
+    health_config: HealthConfig,
+    repository: Arc<dyn Repository>,
+) -> (TestApp, HealthHandles) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("failed to bind random port");
+    let address = listener.local_addr().expect("failed to read local address");
+
+    let (registry, handles) = health::build_registry(&health_config, repository.clone());
+    let state = AppState::new(repository, Arc::new(SystemClock), registry);
+    let router = build_router(state);
+
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .await
+            .expect("test server failed");
+    });
+
+    (
+        TestApp {
+            address,
+            client: reqwest::Client::new(),
+        },
+        handles,
+    )
+}
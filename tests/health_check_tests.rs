@@ -1,19 +1,17 @@
-// Minimal Rust test scaffold (dry-run)
-#[test]
-fn health_endpoint_returns_ok() {
-    // In the real repo this would issue an HTTP request against the handler.
-    // For the dry-run we assert that the test would check status and body.
-    assert_eq!(200, 200);
+mod common;
+
+use common::spawn_app;
+use flow_studio_dev::assert_api;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct HealthResponse {
+    status: String,
 }
-#[cfg(test)]
-mod tests {
-    use reqwest;
 
-    #[tokio::test]
-    async fn health_returns_ok() {
-        // This is a scaffolded integration-style test; in a real run it would start the server.
-        // For the dry-run we assert that the test would check status and body.
-        let _note = "Test should perform GET /health and assert 200 + {status: \"ok\"}";
-        assert!(true);
-    }
+#[tokio::test]
+async fn health_returns_ok() {
+    let app = spawn_app().await;
+
+    assert_api! { app, GET "/health" => HealthResponse { status: "ok".to_string(), .. } }
 }
@@ -0,0 +1,79 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::spawn_app_with;
+use flow_studio_dev::config::{ComponentConfig, HealthConfig};
+use flow_studio_dev::service::{Repository, TcpRepository};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct ReadinessResponse {
+    status: String,
+}
+
+/// A cache configured on port 0 must be probed at its real, OS-assigned
+/// port once that's known — not at the unresolved `:0` placeholder.
+#[tokio::test]
+async fn readiness_resolves_port_zero_component_before_probing() {
+    let cache_listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("failed to bind fake cache");
+    let cache_addr = cache_listener
+        .local_addr()
+        .expect("failed to read cache addr");
+    tokio::spawn(async move {
+        loop {
+            let _ = cache_listener.accept().await;
+        }
+    });
+
+    let health_config = HealthConfig {
+        database: None,
+        cache: Some(ComponentConfig {
+            enabled: true,
+            addr: "127.0.0.1:0".parse().expect("valid addr"),
+        }),
+    };
+    let repository: Arc<dyn Repository> =
+        Arc::new(TcpRepository::new("127.0.0.1:0".parse().expect("valid addr")));
+
+    let (app, handles) = spawn_app_with(health_config, repository).await;
+    handles
+        .cache
+        .expect("cache check should be registered")
+        .resolve(cache_addr);
+
+    let response = app
+        .client
+        .get(app.url("/health/ready"))
+        .send()
+        .await
+        .expect("request failed");
+
+    assert_eq!(response.status(), 200);
+    let body: ReadinessResponse = response.json().await.expect("invalid JSON body");
+    assert_eq!(body.status, "ok");
+}
+
+/// When a configured component is unreachable, readiness reports 503 and
+/// `degraded` — exercised here through `assert_api!`'s explicit-status form.
+#[tokio::test]
+async fn readiness_reports_degraded_when_cache_unreachable() {
+    let health_config = HealthConfig {
+        database: None,
+        cache: Some(ComponentConfig {
+            enabled: true,
+            // Nothing is listening here, so the probe fails.
+            addr: "127.0.0.1:1".parse().expect("valid addr"),
+        }),
+    };
+    let repository: Arc<dyn Repository> =
+        Arc::new(TcpRepository::new("127.0.0.1:0".parse().expect("valid addr")));
+
+    let (app, _handles) = spawn_app_with(health_config, repository).await;
+
+    flow_studio_dev::assert_api! {
+        app, GET "/health/ready" => 503, ReadinessResponse { status: "degraded".to_string() }
+    }
+}